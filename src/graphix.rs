@@ -1,11 +1,50 @@
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct GraphRep<K> {
     v: Vec<usize>,
     e: Vec<(usize, K, usize)>,
     pub id: Vec<(usize, usize, K)>,
+    // Half-edges added by `insert_edge` since the last full CSR build,
+    // kept per-vertex so a single insertion doesn't touch `v`/`e` at all.
+    overflow: Vec<Vec<(usize, K, usize)>>,
+    // Edge ids removed by `remove_edge`; half-edges referencing them are
+    // tombstoned in place and filtered out lazily by `edges_from`.
+    removed: std::collections::HashSet<usize>,
+    // Count of edges ever removed, including ones already folded away by
+    // a past `compact()`. Unlike `removed`, this never gets cleared, so
+    // `num_edges` stays correct across compaction.
+    removed_total: usize,
+}
+
+// Once the overflow (pending inserted half-edges) or the tombstone set
+// (pending removes) grows past this many entries, the `insert_edge` or
+// `remove_edge` call that pushed it past triggers a full compaction back
+// into the plain `v`/`e` CSR form.
+const MUTATION_COMPACTION_THRESHOLD: usize = 64;
+
+/// Controls how `edges_from(u)` orders (and possibly collapses) the
+/// half-edges scattered for vertex `u` during construction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsrLayout {
+    /// Half-edges appear in scatter order (the historical behavior).
+    Unsorted,
+    /// Half-edges are sorted by target vertex, enabling `binary_search`.
+    Sorted,
+    /// Sorted, and parallel edges to the same target are collapsed into
+    /// the single half-edge with the minimum weight.
+    SortedDeduped,
 }
 
 impl<K: PartialOrd + Copy> GraphRep<K> {
-    pub fn edges_from(&self, vertex: usize) -> &[(usize, K, usize)] {
+    /// Neighbors of `vertex`, as `(target, weight, edge_id)` triples.
+    ///
+    /// Combines the compacted CSR slice with any pending `insert_edge`
+    /// overflow for `vertex` and skips half-edges tombstoned by
+    /// `remove_edge`. Note that half-edges from `insert_edge` are appended
+    /// after the compacted slice, and compaction does not re-sort, so
+    /// after an insertion the `Sorted`/`SortedDeduped` ordering from
+    /// `from_list_with_layout` no longer holds over the full result.
+    /// Skipping tombstoned half-edges keeps the order of the rest.
+    pub fn edges_from(&self, vertex: usize) -> Vec<(usize, K, usize)> {
         if vertex + 1 >= self.v.len() {
             panic!(
                 "edges_from(): vertex {} out of range (v.len() = {})",
@@ -15,8 +54,21 @@ impl<K: PartialOrd + Copy> GraphRep<K> {
         }
         let edges_start = self.v[vertex];
         let edges_end = self.v[vertex + 1];
+        let compacted = &self.e[edges_start..edges_end];
+
+        // Common case (freshly built or just-compacted graph): nothing to
+        // fold in or filter out, so just clone the compacted slice instead
+        // of paying for a chain + filter over it.
+        if self.overflow[vertex].is_empty() && self.removed.is_empty() {
+            return compacted.to_vec();
+        }
 
-        &self.e[edges_start..edges_end]
+        compacted
+            .iter()
+            .chain(self.overflow[vertex].iter())
+            .filter(|&&(_, _, eid)| !self.removed.contains(&eid))
+            .copied()
+            .collect()
     }
 
     pub fn original_edge(&self, edge_id: usize) -> Option<&(usize, usize, K)> {
@@ -28,7 +80,7 @@ impl<K: PartialOrd + Copy> GraphRep<K> {
     }
 
     pub fn num_edges(&self) -> usize {
-        self.e.len() / 2
+        self.id.len() - self.removed_total
     }
 
     pub fn v_len(&self) -> usize {
@@ -48,6 +100,9 @@ impl<K: PartialOrd + Copy> GraphRep<K> {
                 v: vec![0], // one offset, so edges_from(u) is never OOB
                 e: Vec::new(),
                 id: Vec::new(),
+                overflow: Vec::new(),
+                removed: std::collections::HashSet::new(),
+                removed_total: 0,
             };
         }
         let id = edges;
@@ -89,13 +144,79 @@ impl<K: PartialOrd + Copy> GraphRep<K> {
             write_cursor[dst] += 1;
         }
 
-        GraphRep { v, e, id }
+        let overflow = vec![Vec::new(); n];
+        GraphRep {
+            v,
+            e,
+            id,
+            overflow,
+            removed: std::collections::HashSet::new(),
+            removed_total: 0,
+        }
+    }
+
+    /// Like `from_list`, but lets the caller pick the adjacency layout.
+    /// `Sorted` and `SortedDeduped` make `edges_from` usable with
+    /// `binary_search` and give a canonical form for comparing graphs.
+    pub fn from_list_with_layout(edges: Vec<(usize, usize, K)>, layout: CsrLayout) -> Self {
+        let mut g = Self::from_list(edges);
+        match layout {
+            CsrLayout::Unsorted => {}
+            CsrLayout::Sorted => g.sort_adjacency(),
+            CsrLayout::SortedDeduped => {
+                g.sort_adjacency();
+                g.dedupe_adjacency();
+            }
+        }
+        g
+    }
+
+    fn sort_adjacency(&mut self) {
+        let n = self.num_vertices();
+        for u in 0..n {
+            let start = self.v[u];
+            let end = self.v[u + 1];
+            self.e[start..end].sort_by_key(|&(to, _, _)| to);
+        }
+    }
+
+    // Collapses runs of identical targets (post `sort_adjacency`) into a
+    // single half-edge, keeping the minimum-weight one.
+    fn dedupe_adjacency(&mut self) {
+        let mut new_v = Vec::with_capacity(self.v.len());
+        let mut new_e = Vec::with_capacity(self.e.len());
+
+        for window in self.v.windows(2) {
+            let (start, end) = (window[0], window[1]);
+            new_v.push(new_e.len());
+            let slice = &self.e[start..end];
+
+            let mut i = 0;
+            while i < slice.len() {
+                let mut best = slice[i];
+                let mut j = i + 1;
+                while j < slice.len() && slice[j].0 == best.0 {
+                    if slice[j].1 < best.1 {
+                        best = slice[j];
+                    }
+                    j += 1;
+                }
+                new_e.push(best);
+                i = j;
+            }
+        }
+        new_v.push(new_e.len());
+
+        self.v = new_v;
+        self.e = new_e;
     }
 
     pub fn update_v_e(&mut self, edges: &[(usize, usize, K, usize)]) {
         if edges.is_empty() {
             self.v = vec![0]; // no vertices left
             self.e.clear(); // clear edge list
+            self.overflow.clear();
+            self.removed.clear();
             return; // exit early
         }
 
@@ -137,6 +258,79 @@ impl<K: PartialOrd + Copy> GraphRep<K> {
         // Replace current CSR layout with newly built one
         self.v = v;
         self.e = e;
+        self.overflow = vec![Vec::new(); n];
+        self.removed.clear();
+    }
+
+    /// Appends `(u, v, w)` as a new edge without rebuilding the CSR
+    /// arrays: the half-edges are stashed in each endpoint's overflow
+    /// list and only folded into `v`/`e` once the overflow grows past
+    /// `MUTATION_COMPACTION_THRESHOLD`. Returns the new edge's stable id,
+    /// which indexes into `self.id` just like ids produced by
+    /// `from_list`.
+    pub fn insert_edge(&mut self, u: usize, v: usize, w: K) -> usize {
+        let needed = u.max(v) + 1;
+        if needed > self.num_vertices() {
+            self.grow_to(needed);
+        }
+
+        let edge_id = self.id.len();
+        self.id.push((u, v, w));
+        self.overflow[u].push((v, w, edge_id));
+        self.overflow[v].push((u, w, edge_id));
+
+        if self.overflow.iter().map(Vec::len).sum::<usize>() > MUTATION_COMPACTION_THRESHOLD {
+            self.compact();
+        }
+
+        edge_id
+    }
+
+    /// Tombstones `edge_id` so `edges_from`, `current_edges` and
+    /// `num_edges` stop reporting it, without touching `v`/`e` yet.
+    /// Triggers a full compaction once enough edges have piled up as
+    /// tombstones.
+    pub fn remove_edge(&mut self, edge_id: usize) {
+        if edge_id >= self.id.len() {
+            panic!(
+                "remove_edge(): edge_id {} out of range (id.len() = {})",
+                edge_id,
+                self.id.len()
+            );
+        }
+
+        if self.removed.insert(edge_id) {
+            self.removed_total += 1;
+        }
+
+        if self.removed.len() > MUTATION_COMPACTION_THRESHOLD {
+            self.compact();
+        }
+    }
+
+    // Grows `v`/`overflow` to cover vertices up to (but not including)
+    // `new_n`, giving the new vertices zero degree in the compacted part.
+    fn grow_to(&mut self, new_n: usize) {
+        let last_offset = *self.v.last().unwrap_or(&0);
+        self.v.resize(new_n + 1, last_offset);
+        self.overflow.resize(new_n, Vec::new());
+    }
+
+    // Rebuilds the plain CSR form from the currently-live edges, folding
+    // the overflow back into `v`/`e` and clearing the tombstone set.
+    //
+    // `update_v_e` derives its vertex count purely from the max vertex id
+    // appearing in `live_edges`, so a vertex that's isolated (its only
+    // edge was just removed, or it was only ever grown in via
+    // `insert_edge`) would otherwise vanish here. Vertex ids must only
+    // grow, so restore any trailing vertices `update_v_e` dropped.
+    fn compact(&mut self) {
+        let n_before = self.num_vertices();
+        let live_edges = self.current_edges();
+        self.update_v_e(&live_edges);
+        if self.num_vertices() < n_before {
+            self.grow_to(n_before);
+        }
     }
 
     ///returns all original edges
@@ -158,7 +352,7 @@ impl<K: PartialOrd + Copy> GraphRep<K> {
         let n = self.num_vertices();
 
         for u in 0..n {
-            for &(v, w, eid) in self.edges_from(u) {
+            for (v, w, eid) in self.edges_from(u) {
                 if u < v {
                     // keep one direction only
                     out.push((u, v, w, eid));
@@ -169,9 +363,591 @@ impl<K: PartialOrd + Copy> GraphRep<K> {
     }
 }
 
+// Below this edge count, spinning up a thread pool costs more than the
+// sequential build it would save; `from_list_par` falls back to `from_list`.
+#[cfg(feature = "rayon")]
+const PAR_BUILD_EDGE_THRESHOLD: usize = 1_000_000;
+
+// A raw-pointer view over a slice that lets multiple threads write to
+// disjoint indices concurrently. Safe only because `from_list_par` hands
+// out each index exactly once (via the atomic per-vertex cursors), so
+// there is never a data race despite the shared mutable access.
+#[cfg(feature = "rayon")]
+struct RacySlice<T> {
+    ptr: *mut T,
+    len: usize,
+}
+
+#[cfg(feature = "rayon")]
+unsafe impl<T: Send> Sync for RacySlice<T> {}
+
+#[cfg(feature = "rayon")]
+impl<T> RacySlice<T> {
+    fn new(slice: &mut [T]) -> Self {
+        RacySlice {
+            ptr: slice.as_mut_ptr(),
+            len: slice.len(),
+        }
+    }
+
+    // SAFETY: caller must guarantee `idx` is written by exactly one thread
+    // over the lifetime of this `RacySlice`.
+    unsafe fn write(&self, idx: usize, val: T) {
+        debug_assert!(idx < self.len);
+        unsafe { self.ptr.add(idx).write(val) };
+    }
+}
+
+#[cfg(feature = "rayon")]
+impl<K: PartialOrd + Copy + Send + Sync> GraphRep<K> {
+    /// Builds the same `v` offsets and the same per-vertex half-edges as
+    /// `from_list` (the order inside each vertex's slice of `e` depends
+    /// on thread scheduling, so it can differ), but parallelizes the
+    /// degree count and the scatter with `rayon`: each thread accumulates
+    /// its own degree array (merged with a parallel reduce), and the
+    /// scatter claims slots in `e` via atomic fetch-add on per-vertex
+    /// write cursors. The prefix sum over `v` stays sequential since it's
+    /// `O(V)` and inherently data-dependent. Below
+    /// `PAR_BUILD_EDGE_THRESHOLD` edges this just calls `from_list`.
+    pub fn from_list_par(edges: Vec<(usize, usize, K)>) -> Self {
+        Self::from_list_par_with_threshold(edges, PAR_BUILD_EDGE_THRESHOLD)
+    }
+
+    // Test seam for `from_list_par`: lets tests force the parallel scatter
+    // path on a small edge list instead of needing a million-edge graph to
+    // cross `PAR_BUILD_EDGE_THRESHOLD`.
+    fn from_list_par_with_threshold(edges: Vec<(usize, usize, K)>, threshold: usize) -> Self {
+        use rayon::prelude::*;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let m = edges.len();
+        if m == 0 || m < threshold {
+            return Self::from_list(edges);
+        }
+
+        let id = edges;
+        let n = id
+            .iter()
+            .flat_map(|&(u, v, _)| [u, v])
+            .max()
+            .map_or(0, |mx| mx + 1);
+
+        // 1) parallel reduction: each thread folds into its own degree
+        // array, which are then merged pairwise.
+        let degree = id
+            .par_iter()
+            .fold(
+                || vec![0usize; n + 1],
+                |mut acc, &(u, vtx, _)| {
+                    acc[u] += 1;
+                    acc[vtx] += 1;
+                    acc
+                },
+            )
+            .reduce(
+                || vec![0usize; n + 1],
+                |mut a, b| {
+                    for (slot, add) in a.iter_mut().zip(b) {
+                        *slot += add;
+                    }
+                    a
+                },
+            );
+
+        // 2) sequential prefix sum over `v` (same shape as `from_list`)
+        let mut v = vec![0usize; n + 1];
+        let mut running_sum = 0;
+        for i in 0..=n {
+            v[i] = running_sum;
+            running_sum += degree[i];
+        }
+
+        // 3) parallel scatter: every vertex gets an atomic write cursor
+        // starting at its CSR offset, and each edge claims one slot per
+        // endpoint via `fetch_add`, so no two edges ever target the same
+        // index in `e`.
+        let cursors: Vec<AtomicUsize> = v.iter().map(|&off| AtomicUsize::new(off)).collect();
+        let mut e = vec![(0usize, id[0].2, 0usize); 2 * m];
+        let e_view = RacySlice::new(&mut e);
+
+        id.par_iter().enumerate().for_each(|(edge_id, &(u, vtx, w))| {
+            let pos_fwd = cursors[u].fetch_add(1, Ordering::Relaxed);
+            // SAFETY: `fetch_add` hands out each offset in `[v[u], v[u+1])`
+            // to exactly one edge, so `pos_fwd` is unique across all threads.
+            unsafe { e_view.write(pos_fwd, (vtx, w, edge_id)) };
+
+            let pos_back = cursors[vtx].fetch_add(1, Ordering::Relaxed);
+            // SAFETY: same argument as above, for vertex `vtx`.
+            unsafe { e_view.write(pos_back, (u, w, edge_id)) };
+        });
+
+        GraphRep {
+            v,
+            e,
+            id,
+            overflow: vec![Vec::new(); n],
+            removed: std::collections::HashSet::new(),
+            removed_total: 0,
+        }
+    }
+}
+
+impl<K> GraphRep<K>
+where
+    K: PartialOrd + Copy + Ord + Default + std::ops::Add<Output = K>,
+{
+    /// Single-source shortest paths via Dijkstra's algorithm, using a
+    /// binary heap of `(dist, vertex)` and skipping stale pops (where the
+    /// popped distance exceeds what's already recorded). Returns
+    /// `(dist, pred)`: `dist[u]` is the shortest distance from `source`
+    /// (left as `K::default()` for vertices never reached) and `pred[u]`
+    /// is `u`'s predecessor on that path (`usize::MAX` for `source` and
+    /// for unreached vertices).
+    pub fn dijkstra(&self, source: usize) -> (Vec<K>, Vec<usize>) {
+        let n = self.num_vertices();
+        if source >= n {
+            panic!(
+                "dijkstra(): source {} out of range (num_vertices = {})",
+                source, n
+            );
+        }
+
+        let mut dist: Vec<Option<K>> = vec![None; n];
+        let mut pred = vec![usize::MAX; n];
+        let mut heap = std::collections::BinaryHeap::new();
+
+        dist[source] = Some(K::default());
+        heap.push(std::cmp::Reverse((K::default(), source)));
+
+        while let Some(std::cmp::Reverse((d, u))) = heap.pop() {
+            if dist[u].is_some_and(|best| d > best) {
+                continue; // stale entry, a better path was already found
+            }
+
+            for (vtx, w, _) in self.edges_from(u) {
+                let cand = d + w;
+                if dist[vtx].is_none_or(|best| cand < best) {
+                    dist[vtx] = Some(cand);
+                    pred[vtx] = u;
+                    heap.push(std::cmp::Reverse((cand, vtx)));
+                }
+            }
+        }
+
+        let dist = dist.into_iter().map(Option::unwrap_or_default).collect();
+        (dist, pred)
+    }
+
+    /// Convenience wrapper over `dijkstra`: the shortest distance and
+    /// vertex path from `src` to `dst`, or `None` if `dst` is unreachable.
+    pub fn shortest_path(&self, src: usize, dst: usize) -> Option<(K, Vec<usize>)> {
+        let (dist, pred) = self.dijkstra(src);
+
+        if src != dst && pred[dst] == usize::MAX {
+            return None;
+        }
+
+        let mut path = vec![dst];
+        let mut cur = dst;
+        while cur != src {
+            cur = pred[cur];
+            path.push(cur);
+        }
+        path.reverse();
+
+        Some((dist[dst], path))
+    }
+}
+
+// Disjoint-set find with path halving, used by `mst` to track which
+// original vertices have already been merged into the same component.
+fn find(parent: &mut [usize], mut x: usize) -> usize {
+    while parent[x] != x {
+        parent[x] = parent[parent[x]];
+        x = parent[x];
+    }
+    x
+}
+
+// Union by rank over the representatives of `a` and `b`.
+fn union(parent: &mut [usize], rank: &mut [u8], a: usize, b: usize) {
+    let (ra, rb) = (find(parent, a), find(parent, b));
+    if ra == rb {
+        return;
+    }
+    match rank[ra].cmp(&rank[rb]) {
+        std::cmp::Ordering::Less => parent[ra] = rb,
+        std::cmp::Ordering::Greater => parent[rb] = ra,
+        std::cmp::Ordering::Equal => {
+            parent[rb] = ra;
+            rank[ra] += 1;
+        }
+    }
+}
+
+impl<K: PartialOrd + Copy + Ord> GraphRep<K> {
+    /// Minimum spanning tree via Borůvka's algorithm: each round finds
+    /// the globally cheapest outgoing edge of every component (ties
+    /// broken by `eid` for determinism), adds the winners, then
+    /// contracts the graph by relabeling vertices to their component
+    /// representative, dropping self-loops, and feeding the survivors
+    /// back through `update_v_e`. Repeats until one component remains.
+    /// Returns the chosen original `edge_id`s, indexing into `self.id`.
+    pub fn mst(&self) -> Vec<usize> {
+        let n = self.num_vertices();
+        let mut result = Vec::new();
+        if n == 0 {
+            return result;
+        }
+
+        let mut parent: Vec<usize> = (0..n).collect();
+        let mut rank = vec![0u8; n];
+        let mut num_components = n;
+
+        let mut work = GraphRep::from_list(Vec::new());
+        work.update_v_e(&self.current_edges());
+
+        while num_components > 1 {
+            let cur_edges = work.current_edges();
+            if cur_edges.is_empty() {
+                break; // disconnected: no edges left to merge remaining components
+            }
+
+            // cheapest outgoing edge per component, keyed by component root
+            let mut best: std::collections::HashMap<usize, (K, usize, usize, usize)> =
+                std::collections::HashMap::new();
+            for &(u, v, w, eid) in &cur_edges {
+                let ru = find(&mut parent, u);
+                let rv = find(&mut parent, v);
+                if ru == rv {
+                    continue;
+                }
+                for root in [ru, rv] {
+                    best.entry(root)
+                        .and_modify(|cur| {
+                            if w < cur.0 || (w == cur.0 && eid < cur.1) {
+                                *cur = (w, eid, u, v);
+                            }
+                        })
+                        .or_insert((w, eid, u, v));
+                }
+            }
+            if best.is_empty() {
+                break; // no cross-component edges left: disconnected
+            }
+
+            let mut merged_any = false;
+            for (_, (_, eid, u, v)) in best {
+                let ru = find(&mut parent, u);
+                let rv = find(&mut parent, v);
+                if ru == rv {
+                    continue; // already merged via the other endpoint this round
+                }
+                result.push(eid);
+                union(&mut parent, &mut rank, ru, rv);
+                num_components -= 1;
+                merged_any = true;
+            }
+            if !merged_any {
+                break;
+            }
+
+            // relabel surviving edges to component representatives, drop
+            // self-loops, and contract `work` for the next round
+            let mut new_edges = Vec::with_capacity(cur_edges.len());
+            for (u, v, w, eid) in cur_edges {
+                let ru = find(&mut parent, u);
+                let rv = find(&mut parent, v);
+                if ru != rv {
+                    new_edges.push((ru, rv, w, eid));
+                }
+            }
+            work.update_v_e(&new_edges);
+        }
+
+        result
+    }
+}
+
+impl<K> GraphRep<K>
+where
+    K: PartialOrd + Copy + Ord + Default + std::ops::Add<Output = K>,
+{
+    /// Global minimum cut via the Stoer–Wagner algorithm. Runs `n - 1`
+    /// phases on a dense adjacency-weight view derived from the CSR
+    /// (multi-edges accumulated into a single weight). Each phase grows a
+    /// "maximum adjacency ordering": repeatedly add the active vertex
+    /// with the largest summed weight to the set already added (tracked
+    /// in `key`), the cut-of-the-phase is the last-added vertex `t`'s key,
+    /// and `t` is then merged into the second-to-last-added vertex `s`.
+    /// Returns the best phase's cut weight and the original vertex ids
+    /// merged into `t` at that point, i.e. one side of the partition.
+    pub fn min_cut(&self) -> (K, Vec<usize>) {
+        let n = self.num_vertices();
+        if n < 2 {
+            return (K::default(), (0..n).collect());
+        }
+
+        // weight[u][v]: combined weight of all edges between u and v
+        let mut weight = vec![vec![K::default(); n]; n];
+        for (u, v, w, _) in self.current_edges() {
+            weight[u][v] = weight[u][v] + w;
+            weight[v][u] = weight[v][u] + w;
+        }
+
+        let mut active: Vec<usize> = (0..n).collect();
+        // merged[r]: original vertex ids currently merged into active vertex r
+        let mut merged: Vec<Vec<usize>> = (0..n).map(|i| vec![i]).collect();
+
+        let mut best_cut: Option<K> = None;
+        let mut best_side = Vec::new();
+
+        while active.len() > 1 {
+            let mut in_a = vec![false; n];
+            let mut key = vec![K::default(); n];
+
+            let first = active[0];
+            in_a[first] = true;
+            for &v in &active {
+                key[v] = weight[first][v];
+            }
+
+            let mut s = first;
+            let mut t = first;
+            for _ in 1..active.len() {
+                let next = *active
+                    .iter()
+                    .filter(|&&v| !in_a[v])
+                    .max_by_key(|&&v| key[v])
+                    .expect("active set shrinks by exactly one vertex per iteration");
+
+                in_a[next] = true;
+                s = t;
+                t = next;
+
+                for &v in &active {
+                    if !in_a[v] {
+                        key[v] = key[v] + weight[next][v];
+                    }
+                }
+            }
+
+            let cut_of_phase = key[t];
+            if best_cut.is_none_or(|best| cut_of_phase < best) {
+                best_cut = Some(cut_of_phase);
+                best_side = merged[t].clone();
+            }
+
+            // merge t into s: fold its weights in and drop the self-loop
+            for &v in &active {
+                if v != s && v != t {
+                    weight[s][v] = weight[s][v] + weight[t][v];
+                    weight[v][s] = weight[v][s] + weight[t][v];
+                }
+            }
+            let absorbed = std::mem::take(&mut merged[t]);
+            merged[s].extend(absorbed);
+            active.retain(|&v| v != t);
+        }
+
+        (best_cut.unwrap_or_default(), best_side)
+    }
+}
+
+/// Types whose values can be written as a fixed-width little-endian byte
+/// sequence, so `to_bytes`/`from_bytes` can serialize them without
+/// depending on `serde`. Implemented for the primitive numeric types
+/// typically used as edge weights.
+pub trait LeBytes: Sized + Copy {
+    const WIDTH: usize;
+    fn write_le_bytes(&self, out: &mut Vec<u8>);
+    fn read_le_bytes(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_le_bytes {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl LeBytes for $t {
+                const WIDTH: usize = std::mem::size_of::<$t>();
+
+                fn write_le_bytes(&self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn read_le_bytes(bytes: &[u8]) -> Self {
+                    let mut buf = [0u8; std::mem::size_of::<$t>()];
+                    buf.copy_from_slice(bytes);
+                    <$t>::from_le_bytes(buf)
+                }
+            }
+        )*
+    };
+}
+
+impl_le_bytes!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+/// Why `from_bytes` rejected a byte slice instead of handing back a
+/// `GraphRep` that would later panic inside `edges_from`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FromBytesError {
+    /// The slice ended before a length-prefixed block was fully read.
+    UnexpectedEof,
+    /// `v` must have at least one offset, even for an empty graph (see
+    /// `from_list`'s `vec![0]`); every other constructor upholds this.
+    OffsetsEmpty,
+    /// `v` must be non-decreasing: it's a CSR prefix-sum over degrees.
+    OffsetsNotMonotonic,
+    /// `v.last()` must equal the number of half-edges in `e`.
+    OffsetsLengthMismatch,
+    /// A half-edge in `e` targets a vertex outside `0..num_vertices()`.
+    TargetOutOfRange { target: usize, num_vertices: usize },
+}
+
+impl std::fmt::Display for FromBytesError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FromBytesError::UnexpectedEof => write!(f, "unexpected end of input"),
+            FromBytesError::OffsetsEmpty => write!(f, "CSR offsets (v) must have at least one entry"),
+            FromBytesError::OffsetsNotMonotonic => write!(f, "CSR offsets (v) are not non-decreasing"),
+            FromBytesError::OffsetsLengthMismatch => {
+                write!(f, "v.last() does not match the number of half-edges in e")
+            }
+            FromBytesError::TargetOutOfRange { target, num_vertices } => write!(
+                f,
+                "half-edge target {target} is out of range (num_vertices = {num_vertices})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FromBytesError {}
+
+// Tracks a read position into a byte slice for `from_bytes`, turning
+// short reads into `FromBytesError::UnexpectedEof` instead of a panic.
+struct ByteCursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteCursor<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], FromBytesError> {
+        let end = self.pos.checked_add(len).ok_or(FromBytesError::UnexpectedEof)?;
+        let slice = self.bytes.get(self.pos..end).ok_or(FromBytesError::UnexpectedEof)?;
+        self.pos = end;
+        Ok(slice)
+    }
+
+    fn take_u64(&mut self) -> Result<u64, FromBytesError> {
+        self.take(8).map(|b| u64::from_le_bytes(b.try_into().unwrap()))
+    }
+}
+
+impl<K: PartialOrd + LeBytes> GraphRep<K> {
+    /// Serializes the compacted CSR form directly: a small header (vertex
+    /// count, edge count), then the `v` offsets, the `e` half-edges, and
+    /// the `id` original-edge list, each as a length-prefixed
+    /// little-endian block. Note this persists `self.v`/`self.e`/`self.id`
+    /// only — any pending `insert_edge` overflow or `remove_edge`
+    /// tombstones are not included, so compact (e.g. via `update_v_e` or
+    /// enough mutations) before calling this if that matters. The count
+    /// of removed edges is not stored either: `from_bytes` reports
+    /// `num_edges() == id.len()`, which includes removed edges' ids.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+
+        out.extend_from_slice(&(self.num_vertices() as u64).to_le_bytes());
+        out.extend_from_slice(&(self.num_edges() as u64).to_le_bytes());
+
+        out.extend_from_slice(&(self.v.len() as u64).to_le_bytes());
+        for &off in &self.v {
+            out.extend_from_slice(&(off as u64).to_le_bytes());
+        }
+
+        out.extend_from_slice(&(self.e.len() as u64).to_le_bytes());
+        for &(target, weight, eid) in &self.e {
+            out.extend_from_slice(&(target as u64).to_le_bytes());
+            weight.write_le_bytes(&mut out);
+            out.extend_from_slice(&(eid as u64).to_le_bytes());
+        }
+
+        out.extend_from_slice(&(self.id.len() as u64).to_le_bytes());
+        for &(u, v, weight) in &self.id {
+            out.extend_from_slice(&(u as u64).to_le_bytes());
+            out.extend_from_slice(&(v as u64).to_le_bytes());
+            weight.write_le_bytes(&mut out);
+        }
+
+        out
+    }
+
+    /// Inverse of `to_bytes`. Validates that `v` is non-decreasing, that
+    /// `v.last() == e.len()`, and that every target in `e` is
+    /// `< num_vertices()`, returning a `FromBytesError` instead of
+    /// producing a `GraphRep` that would panic later inside `edges_from`.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, FromBytesError> {
+        let mut cur = ByteCursor { bytes, pos: 0 };
+
+        let _num_vertices_hdr = cur.take_u64()?;
+        let _num_edges_hdr = cur.take_u64()?;
+
+        let v_len = cur.take_u64()? as usize;
+        let mut v = Vec::with_capacity(v_len);
+        for _ in 0..v_len {
+            v.push(cur.take_u64()? as usize);
+        }
+
+        let e_len = cur.take_u64()? as usize;
+        let mut e = Vec::with_capacity(e_len);
+        for _ in 0..e_len {
+            let target = cur.take_u64()? as usize;
+            let weight = K::read_le_bytes(cur.take(K::WIDTH)?);
+            let eid = cur.take_u64()? as usize;
+            e.push((target, weight, eid));
+        }
+
+        let id_len = cur.take_u64()? as usize;
+        let mut id = Vec::with_capacity(id_len);
+        for _ in 0..id_len {
+            let u = cur.take_u64()? as usize;
+            let vtx = cur.take_u64()? as usize;
+            let weight = K::read_le_bytes(cur.take(K::WIDTH)?);
+            id.push((u, vtx, weight));
+        }
+
+        if v.is_empty() {
+            return Err(FromBytesError::OffsetsEmpty);
+        }
+        if v.windows(2).any(|w| w[0] > w[1]) {
+            return Err(FromBytesError::OffsetsNotMonotonic);
+        }
+        if v.last().copied().unwrap_or(0) != e.len() {
+            return Err(FromBytesError::OffsetsLengthMismatch);
+        }
+        let num_vertices = v.len().saturating_sub(1);
+        for &(target, _, _) in &e {
+            if target >= num_vertices {
+                return Err(FromBytesError::TargetOutOfRange {
+                    target,
+                    num_vertices,
+                });
+            }
+        }
+
+        let overflow = vec![Vec::new(); num_vertices];
+        Ok(GraphRep {
+            v,
+            e,
+            id,
+            overflow,
+            removed: std::collections::HashSet::new(),
+            removed_total: 0,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::GraphRep;
+    use super::{CsrLayout, FromBytesError, GraphRep};
 
     #[test]
     fn test_empty() {
@@ -198,4 +974,304 @@ mod tests {
         adj0.sort_by_key(|&(to, _, eid)| (to, eid));
         assert_eq!(adj0, vec![(1, 1, 0), (2, 3, 2)]);
     }
+
+    #[test]
+    fn test_sorted_layout() {
+        // vertex 0 has neighbors 2 and 1 scattered in that order
+        let edges = vec![(0, 2, 1), (0, 1, 2)];
+        let g = GraphRep::from_list_with_layout(edges, CsrLayout::Sorted);
+
+        let adj0: Vec<_> = g.edges_from(0).iter().map(|&(to, _, _)| to).collect();
+        assert_eq!(adj0, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_sorted_deduped_layout() {
+        // two parallel edges 0–1 with weights 5 and 2; the lighter one should survive
+        let edges = vec![(0, 1, 5), (0, 1, 2)];
+        let g = GraphRep::from_list_with_layout(edges, CsrLayout::SortedDeduped);
+
+        assert_eq!(g.edges_from(0), &[(1, 2, 1)]);
+        assert_eq!(g.edges_from(1), &[(0, 2, 1)]);
+    }
+
+    #[test]
+    fn test_insert_edge_without_compaction() {
+        let edges = vec![(0, 1, 1), (1, 2, 2)];
+        let mut g = GraphRep::from_list(edges);
+
+        let new_id = g.insert_edge(0, 2, 9);
+        assert_eq!(new_id, 2);
+        assert_eq!(g.num_edges(), 3);
+
+        let mut adj0: Vec<_> = g.edges_from(0).into_iter().collect();
+        adj0.sort_by_key(|&(to, _, _)| to);
+        assert_eq!(adj0, vec![(1, 1, 0), (2, 9, 2)]);
+    }
+
+    #[test]
+    fn test_insert_edge_grows_vertex_count() {
+        let mut g: GraphRep<i32> = GraphRep::from_list(vec![(0, 1, 1)]);
+        assert_eq!(g.num_vertices(), 2);
+
+        g.insert_edge(1, 3, 7);
+        assert_eq!(g.num_vertices(), 4);
+        assert_eq!(g.edges_from(3), vec![(1, 7, 1)]);
+    }
+
+    #[test]
+    fn test_remove_edge_is_skipped_everywhere() {
+        let edges = vec![(0, 1, 1), (1, 2, 2), (2, 0, 3)];
+        let mut g = GraphRep::from_list(edges);
+
+        g.remove_edge(1); // the 1–2 edge
+        assert_eq!(g.num_edges(), 2);
+        assert!(g.edges_from(1).iter().all(|&(_, _, eid)| eid != 1));
+        assert!(g.edges_from(2).iter().all(|&(_, _, eid)| eid != 1));
+        assert!(g.current_edges().iter().all(|&(_, _, _, eid)| eid != 1));
+    }
+
+    #[test]
+    fn test_mutation_triggers_compaction() {
+        let mut g: GraphRep<i32> = GraphRep::from_list(vec![(0, 1, 1)]);
+
+        // Each insert adds 2 half-edges to the overflow (one per endpoint);
+        // 33 of them crosses MUTATION_COMPACTION_THRESHOLD and folds the
+        // overflow back into the plain CSR form.
+        for i in 0..33 {
+            g.insert_edge(0, 1, i);
+        }
+
+        assert!(g.overflow.iter().all(Vec::is_empty));
+        assert_eq!(g.num_edges(), 34);
+    }
+
+    #[test]
+    fn test_num_edges_survives_compaction_after_removal() {
+        let mut g: GraphRep<i32> =
+            GraphRep::from_list((0..100).map(|i: usize| (i, i + 1, i as i32)).collect());
+        assert_eq!(g.num_edges(), 100);
+
+        // removing enough edges one at a time crosses
+        // MUTATION_COMPACTION_THRESHOLD and forces a compaction partway
+        // through, which must not forget the edges removed before it ran
+        for eid in 0..65 {
+            g.remove_edge(eid);
+        }
+
+        assert_eq!(g.num_edges(), 35);
+    }
+
+    #[test]
+    fn test_compact_keeps_isolated_vertex() {
+        let mut g: GraphRep<i32> = GraphRep::from_list(vec![(0, 1, 1)]);
+
+        let eid = g.insert_edge(1, 3, 7); // grows the graph to 4 vertices
+        assert_eq!(g.num_vertices(), 4);
+
+        g.remove_edge(eid); // vertex 3 now has no edges at all
+        for i in 0..40 {
+            g.insert_edge(0, 1, i); // forces a compaction
+        }
+
+        assert_eq!(g.num_vertices(), 4);
+        assert_eq!(g.edges_from(3), Vec::new());
+    }
+
+    #[test]
+    fn test_dijkstra_picks_shortest_path() {
+        // 0 -1- 1 -1- 2, and a direct 0 -5- 2 shortcut that isn't shorter
+        let edges = vec![(0, 1, 1), (1, 2, 1), (0, 2, 5)];
+        let g = GraphRep::from_list(edges);
+
+        let (dist, pred) = g.dijkstra(0);
+        assert_eq!(dist, vec![0, 1, 2]);
+        assert_eq!(pred[1], 0);
+        assert_eq!(pred[2], 1);
+    }
+
+    #[test]
+    fn test_shortest_path_walks_predecessors() {
+        let edges = vec![(0, 1, 1), (1, 2, 1), (0, 2, 5)];
+        let g = GraphRep::from_list(edges);
+
+        let (dist, path) = g.shortest_path(0, 2).unwrap();
+        assert_eq!(dist, 2);
+        assert_eq!(path, vec![0, 1, 2]);
+
+        assert_eq!(g.shortest_path(0, 0), Some((0, vec![0])));
+    }
+
+    #[test]
+    fn test_shortest_path_unreachable() {
+        // two disjoint components: 0–1 and 2–3
+        let edges = vec![(0, 1, 1), (2, 3, 1)];
+        let g = GraphRep::from_list(edges);
+
+        assert_eq!(g.shortest_path(0, 3), None);
+    }
+
+    #[test]
+    fn test_mst_on_square_with_diagonal() {
+        // square 0-1-2-3-0 (weight 1 each) plus a heavier diagonal 0-2 (9),
+        // so the MST should be the square minus one side
+        let edges = vec![(0, 1, 1), (1, 2, 1), (2, 3, 1), (3, 0, 1), (0, 2, 9)];
+        let g = GraphRep::from_list(edges);
+
+        let mst = g.mst();
+        assert_eq!(mst.len(), 3);
+
+        let total: i32 = mst.iter().map(|&eid| g.original_edge(eid).unwrap().2).sum();
+        assert_eq!(total, 3);
+        assert!(!mst.contains(&4)); // the expensive diagonal must lose
+    }
+
+    #[test]
+    fn test_mst_disconnected_graph() {
+        // two separate edges: 0-1 and 2-3
+        let edges = vec![(0, 1, 1), (2, 3, 1)];
+        let g = GraphRep::from_list(edges);
+
+        let mst = g.mst();
+        assert_eq!(mst.len(), 2); // one edge per component, never fully connects
+    }
+
+    #[test]
+    fn test_min_cut_finds_the_bridge() {
+        // two tightly-connected triangles {0,1,2} and {3,4,5} joined by a
+        // single cheap bridge edge 2-3; the global min cut must be that bridge
+        let edges = vec![
+            (0, 1, 10),
+            (1, 2, 10),
+            (2, 0, 10),
+            (2, 3, 1),
+            (3, 4, 10),
+            (4, 5, 10),
+            (5, 3, 10),
+        ];
+        let g = GraphRep::from_list(edges);
+
+        let (cut_weight, mut side) = g.min_cut();
+        assert_eq!(cut_weight, 1);
+
+        side.sort_unstable();
+        assert!(side == vec![0, 1, 2] || side == vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_to_bytes_from_bytes_round_trip() {
+        let edges = vec![(0, 1, 1), (1, 2, 2), (2, 0, 3)];
+        let g = GraphRep::from_list(edges.clone());
+
+        let bytes = g.to_bytes();
+        let g2: GraphRep<i32> = GraphRep::from_bytes(&bytes).unwrap();
+
+        assert_eq!(g2.num_vertices(), g.num_vertices());
+        assert_eq!(g2.num_edges(), g.num_edges());
+        assert_eq!(g2.id, edges);
+        for u in 0..g.num_vertices() {
+            assert_eq!(g2.edges_from(u), g.edges_from(u));
+        }
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_truncated_input() {
+        let g: GraphRep<i32> = GraphRep::from_list(vec![(0, 1, 1)]);
+        let mut bytes = g.to_bytes();
+        bytes.truncate(bytes.len() - 1);
+
+        assert_eq!(
+            GraphRep::<i32>::from_bytes(&bytes).err(),
+            Some(FromBytesError::UnexpectedEof)
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_out_of_range_target() {
+        // v says vertex 0 has one half-edge pointing at vertex 5, which
+        // doesn't exist (num_vertices would be 1)
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&1u64.to_le_bytes()); // num_vertices header
+        bytes.extend_from_slice(&1u64.to_le_bytes()); // num_edges header
+        bytes.extend_from_slice(&2u64.to_le_bytes()); // v.len()
+        bytes.extend_from_slice(&0u64.to_le_bytes()); // v[0]
+        bytes.extend_from_slice(&1u64.to_le_bytes()); // v[1]
+        bytes.extend_from_slice(&1u64.to_le_bytes()); // e.len()
+        bytes.extend_from_slice(&5u64.to_le_bytes()); // e[0].target (out of range)
+        bytes.extend_from_slice(&1i32.to_le_bytes()); // e[0].weight
+        bytes.extend_from_slice(&0u64.to_le_bytes()); // e[0].edge_id
+        bytes.extend_from_slice(&0u64.to_le_bytes()); // id.len()
+
+        assert_eq!(
+            GraphRep::<i32>::from_bytes(&bytes).err(),
+            Some(FromBytesError::TargetOutOfRange {
+                target: 5,
+                num_vertices: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_empty_offsets() {
+        // v.len() == 0 and e.len() == 0 would otherwise sail through the
+        // monotonic/length-match/target-range checks and produce a
+        // GraphRep whose num_vertices() panics on subtract-overflow.
+        let mut bytes = Vec::new();
+        bytes.extend_from_slice(&0u64.to_le_bytes()); // num_vertices header
+        bytes.extend_from_slice(&0u64.to_le_bytes()); // num_edges header
+        bytes.extend_from_slice(&0u64.to_le_bytes()); // v.len()
+        bytes.extend_from_slice(&0u64.to_le_bytes()); // e.len()
+        bytes.extend_from_slice(&0u64.to_le_bytes()); // id.len()
+
+        assert_eq!(
+            GraphRep::<i32>::from_bytes(&bytes).err(),
+            Some(FromBytesError::OffsetsEmpty)
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    fn assert_same_adjacency(a: &GraphRep<i32>, b: &GraphRep<i32>) {
+        assert_eq!(a.num_vertices(), b.num_vertices());
+        assert_eq!(a.num_edges(), b.num_edges());
+        for u in 0..a.num_vertices() {
+            let mut a_adj = a.edges_from(u);
+            let mut b_adj = b.edges_from(u);
+            a_adj.sort();
+            b_adj.sort();
+            assert_eq!(a_adj, b_adj);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_from_list_par_fallback_matches_from_list() {
+        // below the (default) threshold, from_list_par just calls from_list
+        let edges = vec![(0, 1, 1), (1, 2, 2), (2, 0, 3)];
+        let par = GraphRep::from_list_par(edges.clone());
+        let seq = GraphRep::from_list(edges);
+
+        assert_same_adjacency(&par, &seq);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_from_list_par_scatter_path_matches_from_list() {
+        // force the parallel degree-count + scatter path on a small graph
+        let edges = vec![(0, 1, 1), (1, 2, 2), (2, 3, 3), (3, 0, 4)];
+        let par = GraphRep::from_list_par_with_threshold(edges.clone(), 0);
+        let seq = GraphRep::from_list(edges);
+
+        assert_same_adjacency(&par, &seq);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_from_list_par_with_threshold_zero_and_empty_edges() {
+        // a zero threshold must still fall back to from_list on an empty
+        // edge list, instead of indexing into the (absent) id[0] while
+        // building the dummy fill value for the parallel scatter path
+        let g: GraphRep<i32> = GraphRep::from_list_par_with_threshold(Vec::new(), 0);
+        assert_eq!(g.num_vertices(), 0);
+        assert_eq!(g.num_edges(), 0);
+    }
 }